@@ -0,0 +1,160 @@
+// Copyright 2020 Tyler Neely
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Approximate size/stat estimation helpers.
+//!
+//! `get_approximate_sizes_with_option` estimates the size of a set of key
+//! ranges, and `get_approximate_memtable_stats_cf` reports the number of
+//! entries and bytes a range currently occupies in the memtables. The
+//! [`IncludeFlags`] carried on each [`Ranges`] lets callers distinguish
+//! unflushed write volume from on-disk SST size, which is useful when deciding
+//! whether to trigger a manual flush or when sizing a compaction before
+//! calling `compact_range`.
+
+use crate::db::DBInner;
+use crate::{ffi, AsColumnFamilyRef, DBCommon, Error, ThreadMode};
+
+use libc::{c_char, c_uchar};
+
+/// Selects which data sources are included when estimating the size of a key
+/// range: the memtables (unflushed writes), the SST files on disk, or both.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IncludeFlags(u8);
+
+impl IncludeFlags {
+    /// Include only data still sitting in the memtables (unflushed writes).
+    pub const MEMTABLES: Self = Self(1);
+    /// Include only data already written to SST files on disk.
+    pub const FILES: Self = Self(2);
+    /// Include both memtables and SST files.
+    pub const BOTH: Self = Self(3);
+
+    fn include_memtables(self) -> bool {
+        self.0 & Self::MEMTABLES.0 != 0
+    }
+
+    fn include_files(self) -> bool {
+        self.0 & Self::FILES.0 != 0
+    }
+}
+
+impl Default for IncludeFlags {
+    fn default() -> Self {
+        Self::BOTH
+    }
+}
+
+/// A `[start, limit)` key range, plus the [`IncludeFlags`] selecting which data
+/// sources should be counted when estimating its size.
+pub struct Ranges<'a> {
+    start: &'a [u8],
+    limit: &'a [u8],
+    include: IncludeFlags,
+}
+
+impl<'a> Ranges<'a> {
+    /// Creates a range covering `[start, limit)`, including both memtables and
+    /// SST files by default.
+    pub fn new(start: &'a [u8], limit: &'a [u8]) -> Self {
+        Ranges {
+            start,
+            limit,
+            include: IncludeFlags::default(),
+        }
+    }
+
+    /// Restricts the estimate for this range to the selected data sources.
+    pub fn with_include(mut self, include: IncludeFlags) -> Self {
+        self.include = include;
+        self
+    }
+}
+
+impl<T: ThreadMode, D: DBInner> DBCommon<T, D> {
+    /// Returns the approximate number of entries and bytes the given `range`
+    /// currently occupies in the memtables of `cf`.
+    ///
+    /// Unlike `get_approximate_sizes_with_option`, which also accounts for SST
+    /// files, this reports only the unflushed write volume.
+    pub fn get_approximate_memtable_stats_cf(
+        &self,
+        cf: &impl AsColumnFamilyRef,
+        range: Ranges,
+    ) -> Result<(u64, u64), Error> {
+        let mut count: u64 = 0;
+        let mut size: u64 = 0;
+        unsafe {
+            ffi::rocksdb_approximate_memtable_stats_cf(
+                self.inner.inner(),
+                cf.inner(),
+                range.start.as_ptr() as *const c_char,
+                range.start.len(),
+                range.limit.as_ptr() as *const c_char,
+                range.limit.len(),
+                &mut count,
+                &mut size,
+            );
+        }
+        Ok((count, size))
+    }
+
+    /// Estimates the size of each `range` in `cf`, honouring the
+    /// [`IncludeFlags`] carried on the range so callers can measure memtables
+    /// and SST files together or in isolation.
+    ///
+    /// `files_error_margin` bounds the error allowed when accounting for SST
+    /// files, as in the underlying `SizeApproximationOptions`.
+    pub fn get_approximate_sizes_with_option(
+        &self,
+        cf: &impl AsColumnFamilyRef,
+        ranges: &[Ranges],
+        files_error_margin: f64,
+    ) -> Result<Vec<u64>, Error> {
+        let mut sizes: Vec<u64> = vec![0; ranges.len()];
+        for (i, range) in ranges.iter().enumerate() {
+            let start = [range.start.as_ptr() as *const c_char];
+            let start_len = [range.start.len()];
+            let limit = [range.limit.as_ptr() as *const c_char];
+            let limit_len = [range.limit.len()];
+            unsafe {
+                let opts = ffi::rocksdb_sizeapproximationoptions_create();
+                ffi::rocksdb_sizeapproximationoptions_set_files_size_error_margin(
+                    opts,
+                    files_error_margin,
+                );
+                ffi::rocksdb_sizeapproximationoptions_set_include_memtables(
+                    opts,
+                    c_uchar::from(range.include.include_memtables()),
+                );
+                ffi::rocksdb_sizeapproximationoptions_set_include_files(
+                    opts,
+                    c_uchar::from(range.include.include_files()),
+                );
+                ffi_try!(ffi::rocksdb_approximate_sizes_cf_with_options(
+                    self.inner.inner(),
+                    opts,
+                    cf.inner(),
+                    1,
+                    start.as_ptr(),
+                    start_len.as_ptr(),
+                    limit.as_ptr(),
+                    limit_len.as_ptr(),
+                    sizes[i..].as_mut_ptr(),
+                ));
+                ffi::rocksdb_sizeapproximationoptions_destroy(opts);
+            }
+        }
+        Ok(sizes)
+    }
+}
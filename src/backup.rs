@@ -0,0 +1,267 @@
+// Copyright 2018 Eugene P.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+
+//! Implementation of bindings to RocksDB BackupEngine[1] API
+//!
+//! Unlike [`Checkpoint`](crate::checkpoint::Checkpoint), which hard-links live
+//! files and therefore needs source and destination on the same filesystem,
+//! the backup engine copies files and supports incremental backups across
+//! filesystems plus restore-to-point, which is what off-box disaster recovery
+//! needs.
+//!
+//! [1]: https://github.com/facebook/rocksdb/wiki/How-to-backup-RocksDB
+
+use crate::db::DBInner;
+use crate::ffi_util::to_cpath;
+use crate::{ffi, DBCommon, Error, ThreadMode};
+
+use libc::{c_int, c_uchar};
+use std::path::Path;
+
+/// Represents information of a backup including timestamp of the backup
+/// and the size (please note that sum of all backups size is not the whole
+/// size of backup directory because of incremental backups feature).
+pub struct BackupEngineInfo {
+    /// Timestamp of the backup
+    pub timestamp: i64,
+    /// Id of the backup
+    pub backup_id: u32,
+    /// Size of the backup
+    pub size: u64,
+    /// Number of files related to the backup
+    pub num_files: u32,
+}
+
+pub struct BackupEngine {
+    inner: *mut ffi::rocksdb_backup_engine_t,
+}
+
+pub struct BackupEngineOptions {
+    inner: *mut ffi::rocksdb_options_t,
+}
+
+pub struct RestoreOptions {
+    inner: *mut ffi::rocksdb_restore_options_t,
+}
+
+impl BackupEngine {
+    /// Open a backup engine with the specified options and backup directory.
+    pub fn open<P: AsRef<Path>>(
+        opts: &BackupEngineOptions,
+        path: P,
+    ) -> Result<BackupEngine, Error> {
+        let cpath = to_cpath(path)?;
+
+        let be: *mut ffi::rocksdb_backup_engine_t;
+        unsafe {
+            be = ffi_try!(ffi::rocksdb_backup_engine_open(opts.inner, cpath.as_ptr()));
+        }
+
+        if be.is_null() {
+            return Err(Error::new("Could not initialize backup engine.".to_owned()));
+        }
+
+        Ok(BackupEngine { inner: be })
+    }
+
+    /// Captures the state of the database in the latest backup.
+    ///
+    /// Note: no flush before backup is performed. User might want to
+    /// use `create_new_backup_flush` instead.
+    pub fn create_new_backup<T: ThreadMode, D: DBInner>(
+        &mut self,
+        db: &DBCommon<T, D>,
+    ) -> Result<(), Error> {
+        unsafe {
+            ffi_try!(ffi::rocksdb_backup_engine_create_new_backup(
+                self.inner,
+                db.inner.inner(),
+            ));
+            Ok(())
+        }
+    }
+
+    /// Captures the state of the database in the latest backup.
+    ///
+    /// Set `flush_before_backup` to `true` to flush the memtable before the
+    /// backup so the write-ahead log is not needed to recover the backup.
+    pub fn create_new_backup_flush<T: ThreadMode, D: DBInner>(
+        &mut self,
+        db: &DBCommon<T, D>,
+        flush_before_backup: bool,
+    ) -> Result<(), Error> {
+        unsafe {
+            ffi_try!(ffi::rocksdb_backup_engine_create_new_backup_flush(
+                self.inner,
+                db.inner.inner(),
+                c_uchar::from(flush_before_backup),
+            ));
+            Ok(())
+        }
+    }
+
+    /// Restore from the backup with `backup_id`.
+    pub fn restore_from_backup<D: AsRef<Path>, W: AsRef<Path>>(
+        &mut self,
+        db_dir: D,
+        wal_dir: W,
+        opts: &RestoreOptions,
+        backup_id: u32,
+    ) -> Result<(), Error> {
+        let db_dir = to_cpath(db_dir)?;
+        let wal_dir = to_cpath(wal_dir)?;
+
+        unsafe {
+            ffi_try!(ffi::rocksdb_backup_engine_restore_db_from_backup(
+                self.inner,
+                db_dir.as_ptr(),
+                wal_dir.as_ptr(),
+                opts.inner,
+                backup_id,
+            ));
+            Ok(())
+        }
+    }
+
+    /// Restore from the latest backup.
+    pub fn restore_from_latest_backup<D: AsRef<Path>, W: AsRef<Path>>(
+        &mut self,
+        db_dir: D,
+        wal_dir: W,
+        opts: &RestoreOptions,
+    ) -> Result<(), Error> {
+        let db_dir = to_cpath(db_dir)?;
+        let wal_dir = to_cpath(wal_dir)?;
+
+        unsafe {
+            ffi_try!(ffi::rocksdb_backup_engine_restore_db_from_latest_backup(
+                self.inner,
+                db_dir.as_ptr(),
+                wal_dir.as_ptr(),
+                opts.inner,
+            ));
+            Ok(())
+        }
+    }
+
+    /// Checks that each file exists and that the size of the file matches our
+    /// expectations. It does not check file checksum.
+    pub fn verify_backup(&self, backup_id: u32) -> Result<(), Error> {
+        unsafe {
+            ffi_try!(ffi::rocksdb_backup_engine_verify_backup(self.inner, backup_id));
+            Ok(())
+        }
+    }
+
+    /// Will delete all the files we don't need anymore. It will keep the last
+    /// `num_backups_to_keep` backups and delete all the other ones.
+    pub fn purge_old_backups(&mut self, num_backups_to_keep: usize) -> Result<(), Error> {
+        unsafe {
+            ffi_try!(ffi::rocksdb_backup_engine_purge_old_backups(
+                self.inner,
+                num_backups_to_keep as u32,
+            ));
+            Ok(())
+        }
+    }
+
+    pub fn get_backup_info(&self) -> Vec<BackupEngineInfo> {
+        unsafe {
+            let i = ffi::rocksdb_backup_engine_get_backup_info(self.inner);
+
+            let n = ffi::rocksdb_backup_engine_info_count(i);
+
+            let mut info = Vec::with_capacity(n as usize);
+            for index in 0..n {
+                info.push(BackupEngineInfo {
+                    timestamp: ffi::rocksdb_backup_engine_info_timestamp(i, index),
+                    backup_id: ffi::rocksdb_backup_engine_info_backup_id(i, index),
+                    size: ffi::rocksdb_backup_engine_info_size(i, index),
+                    num_files: ffi::rocksdb_backup_engine_info_number_files(i, index),
+                });
+            }
+
+            // destroy backup info object
+            ffi::rocksdb_backup_engine_info_destroy(i);
+
+            info
+        }
+    }
+}
+
+impl BackupEngineOptions {
+    /// Initializes `BackupEngineOptions` with default settings.
+    pub fn new() -> BackupEngineOptions {
+        unsafe {
+            let opts = ffi::rocksdb_options_create();
+            assert!(!opts.is_null(), "Could not create RocksDB backup options");
+            BackupEngineOptions { inner: opts }
+        }
+    }
+}
+
+impl RestoreOptions {
+    /// If true, restore won't overwrite the existing log files in wal_dir. It
+    /// will also move all log files from archive directory to wal_dir. By
+    /// default, this is false.
+    pub fn set_keep_log_files(&mut self, keep_log_files: bool) {
+        unsafe {
+            ffi::rocksdb_restore_options_set_keep_log_files(
+                self.inner,
+                c_int::from(keep_log_files),
+            );
+        }
+    }
+}
+
+impl Default for BackupEngineOptions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Default for RestoreOptions {
+    fn default() -> RestoreOptions {
+        unsafe {
+            let opts = ffi::rocksdb_restore_options_create();
+            assert!(!opts.is_null(), "Could not create RocksDB restore options");
+            RestoreOptions { inner: opts }
+        }
+    }
+}
+
+impl Drop for BackupEngine {
+    fn drop(&mut self) {
+        unsafe {
+            ffi::rocksdb_backup_engine_close(self.inner);
+        }
+    }
+}
+
+impl Drop for BackupEngineOptions {
+    fn drop(&mut self) {
+        unsafe {
+            ffi::rocksdb_options_destroy(self.inner);
+        }
+    }
+}
+
+impl Drop for RestoreOptions {
+    fn drop(&mut self) {
+        unsafe {
+            ffi::rocksdb_restore_options_destroy(self.inner);
+        }
+    }
+}
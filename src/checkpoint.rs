@@ -18,7 +18,7 @@
 //! [1]: https://github.com/facebook/rocksdb/wiki/Checkpoints
 
 use crate::AsColumnFamilyRef;
-use crate::{ffi, Error, DB};
+use crate::{ffi, Error, Options, DB};
 use libc::c_char;
 
 use crate::db::DBInner;
@@ -81,19 +81,59 @@ struct RocksdbLevelMetaData {
 }
 
 impl ExportImportFilesMetaData {
-    pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<(), Error> {
-        let mut file =
-            File::create(path).map_err(|_| Error::new("Create metadata file failed".to_owned()))?;
+    /// Marshals the underlying metadata handle back into its JSON
+    /// representation and parses it into `RocksdbExportImportFilesMetaData`.
+    fn to_metadata(&self) -> Result<RocksdbExportImportFilesMetaData, Error> {
         let str = unsafe {
             let c_buf: *const c_char = ffi_try!(ffi::rocksdb_marshal_export_import_files_metadata(
                 self.inner
             ));
             let c_str: &CStr = CStr::from_ptr(c_buf);
-            let str_slice: &str = c_str.to_str().unwrap();
-            str_slice
+            c_str
+                .to_str()
+                .map_err(|_| Error::new("Metadata is not valid UTF-8".to_owned()))?
         };
-        let metadata: RocksdbExportImportFilesMetaData = serde_json::from_str(str).unwrap();
-        file.write_all(serde_json::to_string_pretty(&metadata).unwrap().as_bytes())
+        serde_json::from_str(str)
+            .map_err(|e| Error::new(format!("Failed to parse export metadata: {e}")))
+    }
+
+    /// Verifies that a loaded metadata blob can be safely handed to
+    /// `create_cf_with_import`.
+    ///
+    /// Checks that the embedded `db_comparator_name` matches the target DB's
+    /// comparator and that every referenced SST file in `files` actually
+    /// exists on disk at `directory`/`relative_filename`, returning a
+    /// descriptive [`Error`] instead of failing later inside RocksDB.
+    pub fn verify(&self, db_comparator_name: &str) -> Result<(), Error> {
+        let metadata = self.to_metadata()?;
+
+        if metadata.db_comparator_name != db_comparator_name {
+            return Err(Error::new(format!(
+                "Comparator mismatch: metadata uses `{}` but target DB uses `{}`",
+                metadata.db_comparator_name, db_comparator_name
+            )));
+        }
+
+        for file in &metadata.files {
+            let path = Path::new(&file.directory).join(&file.relative_filename);
+            if !path.exists() {
+                return Err(Error::new(format!(
+                    "Missing SST file referenced by export metadata: {}",
+                    path.display()
+                )));
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<(), Error> {
+        let mut file =
+            File::create(path).map_err(|_| Error::new("Create metadata file failed".to_owned()))?;
+        let metadata = self.to_metadata()?;
+        let serialized = serde_json::to_string_pretty(&metadata)
+            .map_err(|e| Error::new(format!("Failed to serialize export metadata: {e}")))?;
+        file.write_all(serialized.as_bytes())
             .map_err(|_| Error::new("Write metadate file failed".to_owned()))?;
         Ok(())
     }
@@ -105,7 +145,8 @@ impl ExportImportFilesMetaData {
         file.read_to_string(&mut result)
             .map_err(|_| Error::new("Read metadate file failed".to_owned()))?;
 
-        let metadata: RocksdbExportImportFilesMetaData = serde_json::from_str(&result).unwrap();
+        let metadata: RocksdbExportImportFilesMetaData = serde_json::from_str(&result)
+            .map_err(|e| Error::new(format!("Failed to parse export metadata: {e}")))?;
 
         unsafe {
             let mut files = vec![];
@@ -222,6 +263,37 @@ impl<'db> Checkpoint<'db> {
     }
 }
 
+impl DB {
+    /// Imports several column families from their `ExportImportFilesMetaData`
+    /// in a single call, so a full multi-CF export directory produced from one
+    /// checkpoint can be restored atomically.
+    ///
+    /// Each entry is `(name, opts, metadata)`. If any import fails, every
+    /// column family created earlier in the call is dropped again before the
+    /// import error is returned. Errors from those drops are ignored, so a
+    /// column family whose drop fails stays in the DB.
+    pub fn create_cfs_with_import(
+        &mut self,
+        cfs: &[(&str, &Options, &ExportImportFilesMetaData)],
+    ) -> Result<(), Error> {
+        let mut created: Vec<&str> = Vec::with_capacity(cfs.len());
+        for (name, opts, metadata) in cfs {
+            match self.create_cf_with_import(name, opts, metadata) {
+                Ok(()) => created.push(name),
+                Err(e) => {
+                    // Roll back the column families created so far so a partial
+                    // import does not leave the DB in an inconsistent state.
+                    for done in created.iter().rev() {
+                        let _ = self.drop_cf(done);
+                    }
+                    return Err(e);
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
 impl<'db> Drop for Checkpoint<'db> {
     fn drop(&mut self) {
         unsafe {
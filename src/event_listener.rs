@@ -30,6 +30,151 @@ impl FlushJobInfo {
     }
 }
 
+pub struct CompactionJobInfo {
+    pub(crate) inner: *mut ffi::rocksdb_compaction_job_info_t,
+}
+
+impl CompactionJobInfo {
+    pub fn cf_name(&self) -> Vec<u8> {
+        unsafe {
+            let mut name_len = 0;
+            let name = ffi::rocksdb_compaction_job_info_cf_name(self.inner, &mut name_len);
+            if name.is_null() {
+                vec![]
+            } else {
+                let mut vec = vec![0; name_len];
+                std::ptr::copy_nonoverlapping(name as *mut u8, vec.as_mut_ptr(), name_len);
+                ffi::rocksdb_free(name as *mut c_void);
+                vec
+            }
+        }
+    }
+
+    pub fn input_file_count(&self) -> usize {
+        unsafe { ffi::rocksdb_compaction_job_info_input_files_count(self.inner) }
+    }
+
+    pub fn output_file_count(&self) -> usize {
+        unsafe { ffi::rocksdb_compaction_job_info_output_files_count(self.inner) }
+    }
+
+    pub fn input_bytes(&self) -> u64 {
+        unsafe { ffi::rocksdb_compaction_job_info_total_input_bytes(self.inner) }
+    }
+
+    pub fn output_bytes(&self) -> u64 {
+        unsafe { ffi::rocksdb_compaction_job_info_total_output_bytes(self.inner) }
+    }
+
+    pub fn base_input_level(&self) -> i32 {
+        unsafe { ffi::rocksdb_compaction_job_info_base_input_level(self.inner) }
+    }
+
+    pub fn output_level(&self) -> i32 {
+        unsafe { ffi::rocksdb_compaction_job_info_output_level(self.inner) }
+    }
+
+    pub fn compaction_reason(&self) -> i32 {
+        unsafe { ffi::rocksdb_compaction_job_info_compaction_reason(self.inner) }
+    }
+
+    pub fn elapsed_micros(&self) -> u64 {
+        unsafe { ffi::rocksdb_compaction_job_info_elapsed_micros(self.inner) }
+    }
+}
+
+pub struct TableFileCreationInfo {
+    pub(crate) inner: *mut ffi::rocksdb_table_file_creation_info_t,
+}
+
+impl TableFileCreationInfo {
+    pub fn cf_name(&self) -> Vec<u8> {
+        unsafe {
+            let mut name_len = 0;
+            let name = ffi::rocksdb_table_file_creation_info_cf_name(self.inner, &mut name_len);
+            if name.is_null() {
+                vec![]
+            } else {
+                let mut vec = vec![0; name_len];
+                std::ptr::copy_nonoverlapping(name as *mut u8, vec.as_mut_ptr(), name_len);
+                ffi::rocksdb_free(name as *mut c_void);
+                vec
+            }
+        }
+    }
+
+    pub fn file_path(&self) -> Vec<u8> {
+        unsafe {
+            let mut path_len = 0;
+            let path = ffi::rocksdb_table_file_creation_info_file_path(self.inner, &mut path_len);
+            if path.is_null() {
+                vec![]
+            } else {
+                let mut vec = vec![0; path_len];
+                std::ptr::copy_nonoverlapping(path as *mut u8, vec.as_mut_ptr(), path_len);
+                ffi::rocksdb_free(path as *mut c_void);
+                vec
+            }
+        }
+    }
+
+    pub fn file_size(&self) -> u64 {
+        unsafe { ffi::rocksdb_table_file_creation_info_file_size(self.inner) }
+    }
+}
+
+pub struct TableFileDeletionInfo {
+    pub(crate) inner: *mut ffi::rocksdb_table_file_deletion_info_t,
+}
+
+impl TableFileDeletionInfo {
+    pub fn file_path(&self) -> Vec<u8> {
+        unsafe {
+            let mut path_len = 0;
+            let path = ffi::rocksdb_table_file_deletion_info_file_path(self.inner, &mut path_len);
+            if path.is_null() {
+                vec![]
+            } else {
+                let mut vec = vec![0; path_len];
+                std::ptr::copy_nonoverlapping(path as *mut u8, vec.as_mut_ptr(), path_len);
+                ffi::rocksdb_free(path as *mut c_void);
+                vec
+            }
+        }
+    }
+}
+
+pub struct WriteStallInfo {
+    pub(crate) inner: *mut ffi::rocksdb_write_stall_info_t,
+}
+
+impl WriteStallInfo {
+    pub fn cf_name(&self) -> Vec<u8> {
+        unsafe {
+            let mut name_len = 0;
+            let name = ffi::rocksdb_write_stall_info_cf_name(self.inner, &mut name_len);
+            if name.is_null() {
+                vec![]
+            } else {
+                let mut vec = vec![0; name_len];
+                std::ptr::copy_nonoverlapping(name as *mut u8, vec.as_mut_ptr(), name_len);
+                ffi::rocksdb_free(name as *mut c_void);
+                vec
+            }
+        }
+    }
+
+    /// Write-stall condition the column family is transitioning into.
+    pub fn cur(&self) -> i32 {
+        unsafe { ffi::rocksdb_write_stall_info_cur(self.inner) }
+    }
+
+    /// Write-stall condition the column family is transitioning from.
+    pub fn prev(&self) -> i32 {
+        unsafe { ffi::rocksdb_write_stall_info_prev(self.inner) }
+    }
+}
+
 /// EventListener trait contains a set of call-back functions that will
 /// be called when specific RocksDB event happens such as flush.  It can
 /// be used as a building block for developing custom features such as
@@ -42,6 +187,12 @@ impl FlushJobInfo {
 pub trait EventListener: Send + Sync {
     fn on_flush_begin(&self, _: &FlushJobInfo) {}
     fn on_flush_completed(&self, _: &FlushJobInfo) {}
+    fn on_compaction_begin(&self, _: &CompactionJobInfo) {}
+    fn on_compaction_completed(&self, _: &CompactionJobInfo) {}
+    fn on_table_file_created(&self, _: &TableFileCreationInfo) {}
+    fn on_table_file_deleted(&self, _: &TableFileDeletionInfo) {}
+    fn on_stall_conditions_changed(&self, _: &WriteStallInfo) {}
+    fn on_background_error(&self, _reason: i32, _error: &str) {}
 }
 
 pub unsafe extern "C" fn on_flush_completed<L>(
@@ -65,3 +216,117 @@ pub unsafe extern "C" fn on_flush_begin<L>(
     let info = FlushJobInfo { inner: info };
     self_.on_flush_begin(&info);
 }
+
+pub unsafe extern "C" fn on_compaction_begin<L>(
+    raw_self: *mut c_void,
+    info: *mut ffi::rocksdb_compaction_job_info_t,
+) where
+    L: EventListener,
+{
+    let self_ = &mut *(raw_self as *mut L);
+    let info = CompactionJobInfo { inner: info };
+    self_.on_compaction_begin(&info);
+}
+
+pub unsafe extern "C" fn on_compaction_completed<L>(
+    raw_self: *mut c_void,
+    info: *mut ffi::rocksdb_compaction_job_info_t,
+) where
+    L: EventListener,
+{
+    let self_ = &mut *(raw_self as *mut L);
+    let info = CompactionJobInfo { inner: info };
+    self_.on_compaction_completed(&info);
+}
+
+pub unsafe extern "C" fn on_table_file_created<L>(
+    raw_self: *mut c_void,
+    info: *mut ffi::rocksdb_table_file_creation_info_t,
+) where
+    L: EventListener,
+{
+    let self_ = &mut *(raw_self as *mut L);
+    let info = TableFileCreationInfo { inner: info };
+    self_.on_table_file_created(&info);
+}
+
+pub unsafe extern "C" fn on_table_file_deleted<L>(
+    raw_self: *mut c_void,
+    info: *mut ffi::rocksdb_table_file_deletion_info_t,
+) where
+    L: EventListener,
+{
+    let self_ = &mut *(raw_self as *mut L);
+    let info = TableFileDeletionInfo { inner: info };
+    self_.on_table_file_deleted(&info);
+}
+
+pub unsafe extern "C" fn on_stall_conditions_changed<L>(
+    raw_self: *mut c_void,
+    info: *mut ffi::rocksdb_write_stall_info_t,
+) where
+    L: EventListener,
+{
+    let self_ = &mut *(raw_self as *mut L);
+    let info = WriteStallInfo { inner: info };
+    self_.on_stall_conditions_changed(&info);
+}
+
+pub unsafe extern "C" fn on_background_error<L>(
+    raw_self: *mut c_void,
+    reason: i32,
+    status: *mut ffi::rocksdb_status_ptr_t,
+) where
+    L: EventListener,
+{
+    let self_ = &mut *(raw_self as *mut L);
+    let mut err: *mut libc::c_char = std::ptr::null_mut();
+    ffi::rocksdb_status_ptr_get_error(status, &mut err);
+    let msg = if err.is_null() {
+        String::new()
+    } else {
+        let owned = std::ffi::CStr::from_ptr(err).to_string_lossy().into_owned();
+        ffi::rocksdb_free(err as *mut c_void);
+        owned
+    };
+    self_.on_background_error(reason, &msg);
+}
+
+pub unsafe extern "C" fn destructor<L>(raw_self: *mut c_void)
+where
+    L: EventListener,
+{
+    drop(Box::from_raw(raw_self as *mut L));
+}
+
+/// Builds a `rocksdb_eventlistener_t` whose C callbacks are bridged to the
+/// supplied [`EventListener`] and wires every callback to its matching
+/// `rocksdb_eventlistener_set_*` setter.
+///
+/// Ownership of `listener` is transferred to the returned handle; the state is
+/// freed through [`destructor`] when RocksDB destroys the listener (i.e. when
+/// the owning `Options` is dropped). Pass the result to
+/// `rocksdb_options_add_eventlistener`.
+pub fn create_event_listener<L: EventListener>(
+    listener: L,
+) -> *mut ffi::rocksdb_eventlistener_t {
+    let state = Box::into_raw(Box::new(listener)) as *mut c_void;
+    unsafe {
+        let el = ffi::rocksdb_eventlistener_create(state, Some(destructor::<L>));
+        ffi::rocksdb_eventlistener_set_on_flush_begin(el, Some(on_flush_begin::<L>));
+        ffi::rocksdb_eventlistener_set_on_flush_completed(el, Some(on_flush_completed::<L>));
+        ffi::rocksdb_eventlistener_set_on_compaction_begin(el, Some(on_compaction_begin::<L>));
+        ffi::rocksdb_eventlistener_set_on_compaction_completed(
+            el,
+            Some(on_compaction_completed::<L>),
+        );
+        ffi::rocksdb_eventlistener_set_on_table_file_created(el, Some(on_table_file_created::<L>));
+        ffi::rocksdb_eventlistener_set_on_table_file_deleted(el, Some(on_table_file_deleted::<L>));
+        ffi::rocksdb_eventlistener_set_on_stall_conditions_changed(
+            el,
+            Some(on_stall_conditions_changed::<L>),
+        );
+        ffi::rocksdb_eventlistener_set_on_background_error(el, Some(on_background_error::<L>));
+        el
+    }
+}
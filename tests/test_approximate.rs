@@ -3,7 +3,7 @@ use std::time::Duration;
 use gdiist_rocksdb as rocksdb;
 
 use rocksdb::Ranges;
-use rocksdb::{ColumnFamilyDescriptor, Options, DB};
+use rocksdb::{ColumnFamilyDescriptor, IncludeFlags, Options, DB};
 #[test]
 fn test_approximate() {
     let path = "test1";
@@ -51,6 +51,27 @@ fn test_approximate() {
         println!("the size of cf2 with memtable and sstfile is {}", ele);
     }
 
+    // Before flushing, the freshly written keys still live in the memtables.
+    let cf2 = db.cf_handle("cf2").unwrap();
+    db.put_cf(cf2, 10001.to_string(), 20002.to_string()).unwrap();
+    let (count, size) = db
+        .get_approximate_memtable_stats_cf(cf2, Ranges::new(start_key, end_key))
+        .unwrap();
+    println!("cf2 memtable holds ~{} entries / ~{} bytes", count, size);
+
+    // Restrict the sizing query to memtables only to measure unflushed write
+    // volume, independent of SST size.
+    let memtable_only = db
+        .get_approximate_sizes_with_option(
+            cf2,
+            &[Ranges::new(start_key, end_key).with_include(IncludeFlags::MEMTABLES)],
+            files_error_margin,
+        )
+        .unwrap();
+    for ele in memtable_only {
+        println!("the unflushed size of cf2 is {}", ele);
+    }
+
     for key in 0..10000 {
         if key % 2 == 1 {
             db.delete_cf(cf1, key.to_string()).unwrap();
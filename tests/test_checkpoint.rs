@@ -142,6 +142,8 @@ fn test_export_column_family() {
     let result = checkpoint.export_column_family(cf1, &export_path);
     assert!(result.is_ok());
     let metadata = result.unwrap();
+    // Validate the metadata blob before handing it to the importer.
+    assert!(metadata.verify("leveldb.BytewiseComparator").is_ok());
     // println!("metadata {:?}", metadata.save("save"));
     // metadata = ExportImportFilesMetaData::load("save").unwrap();
     // new db from export path
@@ -157,3 +159,45 @@ fn test_export_column_family() {
     assert!(recover_db.cf_handle("cf2").is_none());
     assert!(recover_db.get_cf(&cf1, b"2").unwrap().is_none());
 }
+
+#[test]
+fn test_create_cfs_with_import() {
+    const PATH_PREFIX: &str = "_rust_rocksdb_create_cfs_with_import_";
+
+    // Create DB with two populated column families.
+    let origin_db_path = DBPath::new(&format!("{}db1", PATH_PREFIX));
+
+    let mut opts = Options::default();
+    opts.create_if_missing(true);
+    let origin_db = DB::open(&opts, &origin_db_path).unwrap();
+    assert!(origin_db.create_cf("cf1", &opts).is_ok());
+    assert!(origin_db.create_cf("cf2", &opts).is_ok());
+
+    let cf1 = origin_db.cf_handle("cf1").unwrap();
+    assert!(origin_db.put_cf(&cf1, b"1", b"1").is_ok());
+    let cf2 = origin_db.cf_handle("cf2").unwrap();
+    assert!(origin_db.put_cf(&cf2, b"2", b"2").is_ok());
+
+    let checkpoint = Checkpoint::new(&origin_db).unwrap();
+
+    let export_path1 = DBPath::new(&format!("{}cf1_export", PATH_PREFIX));
+    let metadata1 = checkpoint.export_column_family(cf1, &export_path1).unwrap();
+    assert!(metadata1.verify("leveldb.BytewiseComparator").is_ok());
+    let export_path2 = DBPath::new(&format!("{}cf2_export", PATH_PREFIX));
+    let metadata2 = checkpoint.export_column_family(cf2, &export_path2).unwrap();
+    assert!(metadata2.verify("leveldb.BytewiseComparator").is_ok());
+
+    // Restore both column families atomically into a fresh DB.
+    let recover_db_path = DBPath::new(&format!("{}db1_recover", PATH_PREFIX));
+    let mut recover_db = DB::open(&opts, &recover_db_path).unwrap();
+    let result = recover_db.create_cfs_with_import(&[
+        ("cf1", &opts, &metadata1),
+        ("cf2", &opts, &metadata2),
+    ]);
+    assert!(result.is_ok());
+
+    let cf1 = recover_db.cf_handle("cf1").unwrap();
+    assert_eq!(recover_db.get_cf(&cf1, b"1").unwrap().unwrap(), b"1");
+    let cf2 = recover_db.cf_handle("cf2").unwrap();
+    assert_eq!(recover_db.get_cf(&cf2, b"2").unwrap().unwrap(), b"2");
+}